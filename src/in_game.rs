@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use bevy::{
     app::{App, Plugin, Update},
     color::Color,
@@ -8,7 +10,7 @@ use bevy::{
         query::{Changed, With},
         resource::Resource,
         schedule::{IntoScheduleConfigs, common_conditions::resource_changed},
-        system::{Commands, ParamSet, Query, Res, ResMut},
+        system::{Commands, Query, Res, ResMut},
     },
     input::{
         ButtonInput,
@@ -16,12 +18,13 @@ use bevy::{
         mouse::{MouseButton, MouseWheel},
     },
     log::info,
-    time::{Time, Timer, TimerMode},
+    time::{Stopwatch, Time, Timer, TimerMode},
     ui::{
         BackgroundColor, BorderColor, Interaction, Node, Val, ZIndex,
         widget::{Button, Text},
     },
     utils::default,
+    window::{PrimaryWindow, Window},
 };
 use bevy_state::{
     app::AppExtStates,
@@ -41,7 +44,73 @@ pub struct InGameScene;
 pub struct GridCell {
     pub x: usize,
     pub y: usize,
-    pub life: usize,
+}
+
+// 直前に描画した life 値（変化したセルだけ再描画するため）
+#[derive(Component)]
+pub struct CachedLife(pub u8);
+
+// life 値をグラデーションの色へ変換
+fn life_color(life: u8, max_life: usize) -> Color {
+    if life == 0 {
+        Color::srgba(0.1, 0.1, 0.1, 1.0)
+    } else {
+        let ratio = life as f32 / max_life as f32;
+        Color::srgba(0.2 + 0.6 * ratio, 0.8 * ratio, 0.2 + 0.4 * ratio, 1.0)
+    }
+}
+
+// 近傍数 0..=8 のビットマスク（bit n が立っていれば neighbors == n で条件成立）
+pub type NeighborMask = u16;
+
+pub fn mask_contains(mask: NeighborMask, neighbors: usize) -> bool {
+    neighbors <= 8 && (mask & (1 << neighbors)) != 0
+}
+
+fn mask_range(min: usize, max: usize) -> NeighborMask {
+    let mut mask = 0;
+    for n in min..=max {
+        mask |= 1 << n;
+    }
+    mask
+}
+
+fn parse_digit_mask(digits: &str) -> Option<NeighborMask> {
+    let mut mask = 0;
+    for c in digits.chars() {
+        let n = c.to_digit(10)? as usize;
+        if n > 8 {
+            return None;
+        }
+        mask |= 1 << n;
+    }
+    Some(mask)
+}
+
+// "B.../S..." を (birth_neighbors, survive_neighbors) に変換
+pub fn parse_rulestring(rule: &str) -> Option<(NeighborMask, NeighborMask)> {
+    let mut parts = rule.split('/');
+    let birth_part = parts.next()?.strip_prefix('B')?;
+    let survive_part = parts.next()?.strip_prefix('S')?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((parse_digit_mask(birth_part)?, parse_digit_mask(survive_part)?))
+}
+
+// (birth_neighbors, survive_neighbors) を "B.../S..." に変換
+pub fn format_rulestring(config: &GameConfig) -> String {
+    let digits = |mask: NeighborMask| -> String {
+        (0..=8)
+            .filter(|n| mask_contains(mask, *n))
+            .map(|n| n.to_string())
+            .collect()
+    };
+    format!(
+        "B{}/S{}",
+        digits(config.birth_neighbors),
+        digits(config.survive_neighbors)
+    )
 }
 
 #[derive(Resource)]
@@ -52,14 +121,18 @@ pub struct GameConfig {
     pub heal_amount: usize,
 
     // 生存条件
-    pub survive_neighbors_min: usize,
-    pub survive_neighbors_max: usize,
+    pub survive_neighbors: NeighborMask,
 
     // 誕生条件
-    pub birth_neighbors_min: usize,
-    pub birth_neighbors_max: usize,
+    pub birth_neighbors: NeighborMask,
 
     pub update_interval: f32,
+
+    // true なら端でトーラス状にラップ、false なら境界外として扱う
+    pub wrap: bool,
+
+    // Countdown の長さ（秒）。0 ならカウントダウンを飛ばして即 Processing
+    pub countdown_seconds: f32,
 }
 
 impl Default for GameConfig {
@@ -71,12 +144,12 @@ impl Default for GameConfig {
             heal_amount: 1,
 
             // Conway's Life (B3/S23)
-            survive_neighbors_min: 2,
-            survive_neighbors_max: 3,
-            birth_neighbors_min: 3,
-            birth_neighbors_max: 3,
+            survive_neighbors: mask_range(2, 3),
+            birth_neighbors: mask_range(3, 3),
 
             update_interval: 0.5,
+            wrap: false,
+            countdown_seconds: 3.,
         }
     }
 }
@@ -94,11 +167,88 @@ impl Default for GameUpdateTimer {
     }
 }
 
+// Countdown 中にカウントダウンするタイマー（state 突入時に countdown_seconds でリセット）
+#[derive(Resource)]
+pub(crate) struct CountdownTimer {
+    pub timer: Timer,
+}
+
+impl Default for CountdownTimer {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(0., TimerMode::Once),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, Eq, PartialEq, Hash, States)]
 pub(crate) enum InGameState {
     #[default]
     Stop,
+    Countdown,
     Processing,
+    GameOver,
+}
+
+// 停滞（静止）/ 周期2振動の判定用に直近2世代を保持
+#[derive(Resource, Default)]
+pub(crate) struct GameOverDetector {
+    previous_generation: Vec<u8>,
+    before_previous_generation: Vec<u8>,
+}
+
+// ダブルバッファのフラット配列（y * grid_num + x でインデックス）
+#[derive(Resource, Default)]
+pub struct GameBoard {
+    pub current: Vec<u8>,
+    pub next: Vec<u8>,
+}
+
+impl GameBoard {
+    fn sized(len: usize) -> Self {
+        Self {
+            current: vec![0; len],
+            next: vec![0; len],
+        }
+    }
+}
+
+// (x, y) -> Entity の対応表（セルの手描きで全走査しないため）
+#[derive(Resource, Default)]
+pub(crate) struct GridIndex(HashMap<(usize, usize), Entity>);
+
+// (dx, dy) 方向の近傍インデックス。wrap なしで範囲外なら None
+fn neighbor_index(
+    x: usize,
+    y: usize,
+    dx: isize,
+    dy: isize,
+    grid_num: usize,
+    wrap: bool,
+) -> Option<usize> {
+    let grid_num_i = grid_num as isize;
+    let nx = x as isize + dx;
+    let ny = y as isize + dy;
+
+    let (nx, ny) = if wrap {
+        (nx.rem_euclid(grid_num_i), ny.rem_euclid(grid_num_i))
+    } else {
+        if nx < 0 || ny < 0 || nx >= grid_num_i || ny >= grid_num_i {
+            return None;
+        }
+        (nx, ny)
+    };
+
+    Some(ny as usize * grid_num + nx as usize)
+}
+
+// HUD 表示用の統計（世代数・生存数・最大生存数・経過時間）
+#[derive(Resource, Default)]
+pub struct GameStats {
+    pub generation: u32,
+    pub alive_count: usize,
+    pub peak_alive: usize,
+    pub stopwatch: Stopwatch,
 }
 
 #[derive(Event, Default)]
@@ -111,9 +261,19 @@ impl Plugin for InGamePlugin {
         app.init_state::<InGameState>()
             .init_resource::<GameConfig>()
             .init_resource::<GameUpdateTimer>()
+            .init_resource::<GameOverDetector>()
+            .init_resource::<GameStats>()
+            .init_resource::<GameBoard>()
+            .init_resource::<GridIndex>()
+            .init_resource::<CountdownTimer>()
             .add_plugins(IngameUiPlugin)
             .add_systems(OnEnter(crate::AppState::InGame), setup_in_game_scene)
             .add_systems(OnExit(crate::AppState::InGame), close_in_game)
+            .add_systems(OnEnter(InGameState::Countdown), start_countdown)
+            .add_systems(
+                Update,
+                tick_countdown.run_if(in_state(InGameState::Countdown)),
+            )
             .add_systems(
                 Update,
                 update_game_timer.run_if(in_state(InGameState::Processing)),
@@ -121,15 +281,28 @@ impl Plugin for InGamePlugin {
             .add_systems(
                 Update,
                 update_grid.run_if(in_state(InGameState::Processing)),
+            )
+            .add_systems(
+                Update,
+                handle_cell_painting.run_if(in_state(InGameState::Stop)),
             );
     }
 }
 
-pub(crate) fn setup_in_game_scene(mut commands: Commands, config: Res<GameConfig>) {
+// グリッドをランダムに初期化して GridCell を再スポーン（初期化 / Restart 共通）
+pub(crate) fn spawn_grid_cells(
+    commands: &mut Commands,
+    config: &GameConfig,
+    board: &mut GameBoard,
+    index: &mut GridIndex,
+) -> usize {
     let mut rng = rand::thread_rng();
     let mut alive_count = 0;
     let initial_alive_count = 10000; // 初期に生きているマスの個数
 
+    *board = GameBoard::sized(config.grid_num * config.grid_num);
+    index.0.clear();
+
     for x in 0..config.grid_num {
         for y in 0..config.grid_num {
             let life = if alive_count < initial_alive_count && rng.gen_bool(0.1) {
@@ -138,33 +311,74 @@ pub(crate) fn setup_in_game_scene(mut commands: Commands, config: Res<GameConfig
             } else {
                 0
             };
-
-            commands.spawn((
-                InGameScene,
-                GridCell { x, y, life },
-                Node {
-                    width: Val::Px(10.),
-                    height: Val::Px(10.),
-                    position_type: bevy::ui::PositionType::Absolute,
-                    left: Val::Px(x as f32 * 10.),
-                    bottom: Val::Px(y as f32 * 10.),
-                    ..default()
-                },
-                BackgroundColor(Color::srgba(0.2, 0.2, 0.2, 1.0)),
-                BorderColor::all(Color::BLACK),
-            ));
+            let life = life as u8;
+            board.current[y * config.grid_num + x] = life;
+
+            let entity = commands
+                .spawn((
+                    InGameScene,
+                    GridCell { x, y },
+                    CachedLife(life),
+                    Node {
+                        width: Val::Px(10.),
+                        height: Val::Px(10.),
+                        position_type: bevy::ui::PositionType::Absolute,
+                        left: Val::Px(x as f32 * 10.),
+                        bottom: Val::Px(y as f32 * 10.),
+                        ..default()
+                    },
+                    BackgroundColor(life_color(life, config.max_life)),
+                    BorderColor::all(Color::BLACK),
+                ))
+                .id();
+            index.0.insert((x, y), entity);
         }
     }
 
+    alive_count
+}
+
+pub(crate) fn setup_in_game_scene(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    mut stats: ResMut<GameStats>,
+    mut board: ResMut<GameBoard>,
+    mut index: ResMut<GridIndex>,
+    mut detector: ResMut<GameOverDetector>,
+) {
+    let alive_count = spawn_grid_cells(&mut commands, &config, &mut board, &mut index);
+    *stats = GameStats {
+        alive_count,
+        ..GameStats::default()
+    };
+    *detector = GameOverDetector::default();
+
     info!("Game started with {} alive cells", alive_count);
 }
 
+pub(crate) fn start_countdown(config: Res<GameConfig>, mut countdown: ResMut<CountdownTimer>) {
+    countdown.timer = Timer::from_seconds(config.countdown_seconds, TimerMode::Once);
+}
+
+pub(crate) fn tick_countdown(
+    time: Res<Time>,
+    mut countdown: ResMut<CountdownTimer>,
+    mut next_in_game_state: ResMut<NextState<InGameState>>,
+) {
+    countdown.timer.tick(time.delta());
+    if countdown.timer.finished() {
+        next_in_game_state.set(InGameState::Processing);
+    }
+}
+
 pub fn update_game_timer(
     mut timer: ResMut<GameUpdateTimer>,
     time: Res<Time>,
     config: Res<GameConfig>,
+    mut stats: ResMut<GameStats>,
 ) {
     timer.timer.tick(time.delta());
+    stats.stopwatch.tick(time.delta());
 
     // タイマーの期間を動的に変更したい場合に対応
     if timer.timer.duration().as_secs_f32() != config.update_interval {
@@ -173,101 +387,135 @@ pub fn update_game_timer(
 }
 
 pub fn update_grid(
-    mut param_set: ParamSet<(
-        Query<&GridCell>,
-        Query<&mut GridCell>,
-        Query<(&GridCell, &mut BackgroundColor)>,
-    )>,
+    mut board: ResMut<GameBoard>,
     config: Res<GameConfig>,
     timer: Res<GameUpdateTimer>,
+    mut detector: ResMut<GameOverDetector>,
+    mut stats: ResMut<GameStats>,
+    mut next_in_game_state: ResMut<NextState<InGameState>>,
+    mut cell_query: Query<(&GridCell, &mut CachedLife, &mut BackgroundColor)>,
 ) {
     if !timer.timer.finished() {
         return;
     }
 
-    // 隣接セル数カウント
-    let mut neighbor_counts = std::collections::HashMap::<(usize, usize), usize>::new();
+    let grid_num = config.grid_num;
+    let mut alive_count = 0usize;
 
-    {
-        let query = param_set.p0();
-        for cell in query.iter() {
-            if cell.life > 0 {
+    for y in 0..grid_num {
+        for x in 0..grid_num {
+            let mut neighbors = 0usize;
+            for dy in -1..=1 {
                 for dx in -1..=1 {
-                    for dy in -1..=1 {
-                        if dx == 0 && dy == 0 {
-                            continue;
-                        }
-                        let nx = cell.x as i32 + dx;
-                        let ny = cell.y as i32 + dy;
-
-                        if nx >= 0
-                            && ny >= 0
-                            && (nx as usize) < config.grid_num
-                            && (ny as usize) < config.grid_num
-                        {
-                            *neighbor_counts
-                                .entry((nx as usize, ny as usize))
-                                .or_insert(0) += 1;
+                    if dx == 0 && dy == 0 {
+                        continue;
+                    }
+                    if let Some(neighbor_idx) =
+                        neighbor_index(x, y, dx, dy, grid_num, config.wrap)
+                    {
+                        if board.current[neighbor_idx] > 0 {
+                            neighbors += 1;
                         }
                     }
                 }
             }
-        }
-    }
 
-    // 次世代 life を計算
-    let mut next_life = Vec::new();
-
-    {
-        let query = param_set.p0();
-        for cell in query.iter() {
-            let neighbors = *neighbor_counts.get(&(cell.x, cell.y)).unwrap_or(&0);
-
-            let mut life = cell.life;
+            let idx = y * grid_num + x;
+            let mut life = board.current[idx] as usize;
 
             if life > 0 {
                 // 生存セルの更新
-                if neighbors < config.survive_neighbors_min
-                    || neighbors > config.survive_neighbors_max
-                {
+                if !mask_contains(config.survive_neighbors, neighbors) {
                     life = life.saturating_sub(config.damage_amount);
-                } else if neighbors >= config.birth_neighbors_min
-                    && neighbors <= config.birth_neighbors_max
-                {
+                } else if mask_contains(config.birth_neighbors, neighbors) {
                     life = life.saturating_add(config.heal_amount);
                 }
             } else {
                 // 死亡セルの更新
-                if neighbors >= config.birth_neighbors_min
-                    && neighbors <= config.birth_neighbors_max
-                {
+                if mask_contains(config.birth_neighbors, neighbors) {
                     life = config.max_life;
                 }
             }
 
             life = life.clamp(0, config.max_life);
-            next_life.push(life);
+            board.next[idx] = life as u8;
+
+            if life > 0 {
+                alive_count += 1;
+            }
         }
     }
 
-    // 反映
-    {
-        let mut query = param_set.p1();
-        for (mut cell, life) in query.iter_mut().zip(next_life.into_iter()) {
-            cell.life = life;
+    std::mem::swap(&mut board.current, &mut board.next);
+
+    // 統計更新
+    stats.generation += 1;
+    stats.alive_count = alive_count;
+    stats.peak_alive = stats.peak_alive.max(alive_count);
+
+    // Game Over 判定（死滅 / 静止 / 周期2振動）
+    let is_still_life = board.current == detector.previous_generation;
+    let is_oscillator = board.current == detector.before_previous_generation;
+
+    if alive_count == 0 || is_still_life || is_oscillator {
+        next_in_game_state.set(InGameState::GameOver);
+    }
+
+    detector.before_previous_generation = std::mem::take(&mut detector.previous_generation);
+    detector.previous_generation = board.current.clone();
+
+    // 色更新: 変化したセルのみ再描画
+    for (cell, mut cached, mut bg_color) in cell_query.iter_mut() {
+        let life = board.current[cell.y * grid_num + cell.x];
+        if cached.0 != life {
+            cached.0 = life;
+            bg_color.0 = life_color(life, config.max_life);
         }
     }
+}
 
-    // 色更新（life に応じてグラデーション）
-    {
-        let mut query = param_set.p2();
-        for (cell, mut bg_color) in query.iter_mut() {
-            if cell.life == 0 {
-                bg_color.0 = Color::srgba(0.1, 0.1, 0.1, 1.0);
-            } else {
-                let ratio = cell.life as f32 / config.max_life as f32;
-                bg_color.0 = Color::srgba(0.2 + 0.6 * ratio, 0.8 * ratio, 0.2 + 0.4 * ratio, 1.0);
-            }
+// 左ドラッグで生きさせ、右ドラッグで消す（Stop 中のみ）
+pub fn handle_cell_painting(
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    config: Res<GameConfig>,
+    mut board: ResMut<GameBoard>,
+    index: Res<GridIndex>,
+    mut cell_query: Query<(&mut CachedLife, &mut BackgroundColor)>,
+) {
+    let painting = mouse_button.pressed(MouseButton::Left);
+    let erasing = mouse_button.pressed(MouseButton::Right);
+
+    if !painting && !erasing {
+        return;
+    }
+
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Some(cursor) = window.cursor_position() else {
+        return;
+    };
+
+    let grid_x = (cursor.x / 10.) as isize;
+    // スクリーン座標は上が原点なので、下基準のグリッド座標に変換
+    let grid_y = ((window.height() - cursor.y) / 10.) as isize;
+
+    if grid_x < 0 || grid_y < 0 {
+        return;
+    }
+    let (grid_x, grid_y) = (grid_x as usize, grid_y as usize);
+    if grid_x >= config.grid_num || grid_y >= config.grid_num {
+        return;
+    }
+
+    let new_life = if painting { config.max_life } else { 0 } as u8;
+    board.current[grid_y * config.grid_num + grid_x] = new_life;
+
+    if let Some(&entity) = index.0.get(&(grid_x, grid_y)) {
+        if let Ok((mut cached, mut bg_color)) = cell_query.get_mut(entity) {
+            cached.0 = new_life;
+            bg_color.0 = life_color(new_life, config.max_life);
         }
     }
 }
@@ -12,7 +12,7 @@ use bevy::{
     input::{
         ButtonInput,
         keyboard::KeyCode,
-        mouse::{MouseButton, MouseWheel},
+        mouse::MouseWheel,
     },
     log::info,
     ui::{
@@ -27,7 +27,10 @@ use bevy_state::{
     state::{NextState, OnEnter, OnExit, State, States},
 };
 
-use crate::in_game::{InGameScene, InGameState};
+use crate::in_game::{
+    CountdownTimer, GameBoard, GameConfig, GameOverDetector, GameStats, GridCell, InGameScene,
+    InGameState,
+};
 
 #[derive(Component)]
 enum BottomButtons {
@@ -36,19 +39,82 @@ enum BottomButtons {
     Exit,
 }
 
+#[derive(Component)]
+enum GameOverButtons {
+    Restart,
+    MainMenu,
+}
+
+#[derive(Component)]
+struct GameOverOverlay;
+
+#[derive(Component)]
+struct StatsText;
+
+#[derive(Component)]
+struct CountdownOverlay;
+
+#[derive(Component)]
+struct CountdownText;
+
 pub(crate) struct IngameUiPlugin;
 
 impl Plugin for IngameUiPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(crate::AppState::InGame), setup_in_game_scene)
+            .add_systems(OnEnter(InGameState::GameOver), setup_game_over_overlay)
+            .add_systems(OnExit(InGameState::GameOver), cleanup_game_over_overlay)
+            .add_systems(
+                OnEnter(InGameState::Countdown),
+                setup_countdown_overlay.after(crate::in_game::start_countdown),
+            )
+            .add_systems(OnExit(InGameState::Countdown), cleanup_countdown_overlay)
             .add_systems(
                 Update,
                 handle_button_interaction.run_if(in_state(crate::AppState::InGame)),
+            )
+            .add_systems(
+                Update,
+                handle_game_over_button_interaction.run_if(in_state(crate::AppState::InGame)),
+            )
+            .add_systems(
+                Update,
+                update_stats_text
+                    .run_if(in_state(crate::AppState::InGame))
+                    .run_if(resource_changed::<GameStats>),
+            )
+            .add_systems(
+                Update,
+                handle_mouse_wheel_speed.run_if(in_state(crate::AppState::InGame)),
+            )
+            .add_systems(
+                Update,
+                update_countdown_text.run_if(in_state(InGameState::Countdown)),
             );
     }
 }
 
 pub(crate) fn setup_in_game_scene(mut commands: Commands) {
+    // Top stats HUD
+    commands
+        .spawn((
+            InGameScene,
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Px(24.),
+                top: Val::Px(0.),
+                left: Val::Px(0.),
+                position_type: bevy::ui::PositionType::Absolute,
+                justify_content: bevy::ui::JustifyContent::Center,
+                align_items: bevy::ui::AlignItems::Center,
+                ..default()
+            },
+            ZIndex(100),
+        ))
+        .with_children(|parent| {
+            parent.spawn((StatsText, Text::new("Generation: 0  Alive: 0  Peak: 0  Time: 0.0s")));
+        });
+
     // Footer buttons
     commands
         .spawn((
@@ -101,6 +167,7 @@ pub(crate) fn handle_button_interaction(
         (&Interaction, &BottomButtons, &mut BackgroundColor),
         Changed<Interaction>,
     >,
+    config: Res<GameConfig>,
     mut next_game_state: ResMut<NextState<InGameState>>,
     mut next_app_state: ResMut<NextState<crate::AppState>>,
 ) {
@@ -109,8 +176,13 @@ pub(crate) fn handle_button_interaction(
             Interaction::Pressed => {
                 match button {
                     BottomButtons::Start => {
-                        info!("Game started");
-                        next_game_state.set(InGameState::Processing);
+                        if config.countdown_seconds > 0. {
+                            info!("Game counting down");
+                            next_game_state.set(InGameState::Countdown);
+                        } else {
+                            info!("Game started");
+                            next_game_state.set(InGameState::Processing);
+                        }
                         *bg_color = BackgroundColor(Color::srgba(0.13, 0.49, 0.23, 0.8));
                     }
                     BottomButtons::Stop => {
@@ -132,4 +204,192 @@ pub(crate) fn handle_button_interaction(
             }
         }
     }
+}
+
+pub(crate) fn update_stats_text(
+    stats: Res<GameStats>,
+    mut text_query: Query<&mut Text, With<StatsText>>,
+) {
+    for mut text in text_query.iter_mut() {
+        *text = Text::new(format!(
+            "Generation: {}  Alive: {}  Peak: {}  Time: {:.1}s",
+            stats.generation,
+            stats.alive_count,
+            stats.peak_alive,
+            stats.stopwatch.elapsed_secs()
+        ));
+    }
+}
+
+// ホイール上で加速、下で減速（即時反映）
+pub(crate) fn handle_mouse_wheel_speed(
+    mut wheel_events: EventReader<MouseWheel>,
+    mut config: ResMut<GameConfig>,
+) {
+    for event in wheel_events.read() {
+        config.update_interval = (config.update_interval - event.y * 0.05).clamp(0.05, 2.0);
+    }
+}
+
+pub(crate) fn setup_game_over_overlay(mut commands: Commands, stats: Res<GameStats>) {
+    commands
+        .spawn((
+            InGameScene,
+            GameOverOverlay,
+            Node {
+                width: Val::Percent(40.),
+                height: Val::Percent(40.),
+                top: Val::Percent(30.),
+                left: Val::Percent(30.),
+                position_type: bevy::ui::PositionType::Absolute,
+                flex_direction: bevy::ui::FlexDirection::Column,
+                justify_content: bevy::ui::JustifyContent::Center,
+                align_items: bevy::ui::AlignItems::Center,
+                ..default()
+            },
+            ZIndex(200),
+            BorderColor::all(Color::WHITE),
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.9)),
+        ))
+        .with_children(|parent| {
+            parent.spawn(Text::new("GAME OVER".to_string()));
+            parent.spawn(Text::new(format!(
+                "Generations survived: {}",
+                stats.generation
+            )));
+            parent.spawn(Text::new(format!("Peak population: {}", stats.peak_alive)));
+
+            for button in [
+                ("RESTART", GameOverButtons::Restart),
+                ("MAIN MENU", GameOverButtons::MainMenu),
+            ] {
+                parent.spawn((
+                    button.1,
+                    Button,
+                    Node {
+                        width: Val::Px(160.),
+                        height: Val::Px(30.),
+                        margin: bevy::ui::UiRect {
+                            left: Val::Px(10.),
+                            right: Val::Px(10.),
+                            top: Val::Px(10.),
+                            bottom: Val::Px(0.),
+                        },
+                        justify_content: bevy::ui::JustifyContent::Center,
+                        align_items: bevy::ui::AlignItems::Center,
+                        ..default()
+                    },
+                    BorderColor::all(Color::WHITE),
+                    BackgroundColor(Color::srgba(0.13, 0.49, 0.23, 0.5)),
+                    Text::new(button.0.to_string()),
+                ));
+            }
+        });
+}
+
+pub(crate) fn cleanup_game_over_overlay(
+    mut commands: Commands,
+    query: Query<Entity, With<GameOverOverlay>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+pub(crate) fn setup_countdown_overlay(mut commands: Commands, countdown: Res<CountdownTimer>) {
+    commands
+        .spawn((
+            InGameScene,
+            CountdownOverlay,
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                position_type: bevy::ui::PositionType::Absolute,
+                justify_content: bevy::ui::JustifyContent::Center,
+                align_items: bevy::ui::AlignItems::Center,
+                ..default()
+            },
+            ZIndex(200),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                CountdownText,
+                Text::new(format!(
+                    "{:.0}",
+                    countdown.timer.remaining_secs().ceil()
+                )),
+            ));
+        });
+}
+
+pub(crate) fn cleanup_countdown_overlay(
+    mut commands: Commands,
+    query: Query<Entity, With<CountdownOverlay>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+pub(crate) fn update_countdown_text(
+    countdown: Res<CountdownTimer>,
+    mut text_query: Query<&mut Text, With<CountdownText>>,
+) {
+    for mut text in text_query.iter_mut() {
+        *text = Text::new(format!("{:.0}", countdown.timer.remaining_secs().ceil()));
+    }
+}
+
+pub(crate) fn handle_game_over_button_interaction(
+    mut button_query: Query<
+        (&Interaction, &GameOverButtons, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    mut detector: ResMut<GameOverDetector>,
+    mut stats: ResMut<GameStats>,
+    mut board: ResMut<GameBoard>,
+    mut index: ResMut<crate::in_game::GridIndex>,
+    existing_cells: Query<Entity, With<GridCell>>,
+    mut next_game_state: ResMut<NextState<InGameState>>,
+    mut next_app_state: ResMut<NextState<crate::AppState>>,
+) {
+    for (interaction, button, mut bg_color) in button_query.iter_mut() {
+        match interaction {
+            Interaction::Pressed => {
+                match button {
+                    GameOverButtons::Restart => {
+                        info!("Restarting game");
+                        for entity in existing_cells.iter() {
+                            commands.entity(entity).despawn();
+                        }
+                        let alive_count = crate::in_game::spawn_grid_cells(
+                            &mut commands,
+                            &config,
+                            &mut board,
+                            &mut index,
+                        );
+                        *detector = GameOverDetector::default();
+                        *stats = GameStats {
+                            alive_count,
+                            ..GameStats::default()
+                        };
+                        next_game_state.set(InGameState::Stop);
+                    }
+                    GameOverButtons::MainMenu => {
+                        info!("Returning to main menu");
+                        next_app_state.set(crate::AppState::MainMenu);
+                    }
+                }
+                *bg_color = BackgroundColor(Color::srgba(0.13, 0.49, 0.23, 0.8));
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(Color::srgba(0.13, 0.49, 0.23, 0.9));
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(Color::srgba(0.13, 0.49, 0.23, 0.5));
+            }
+        }
+    }
 }
\ No newline at end of file
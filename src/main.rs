@@ -1,15 +1,17 @@
 use bevy::{prelude::{App, Camera2d, Commands, DefaultPlugins,Startup, }};
 use bevy_state::{app::AppExtStates, state::States};
 
-use crate::{in_game::InGamePlugin, main_menu::MainMenuPlugin};
+use crate::{in_game::InGamePlugin, main_menu::MainMenuPlugin, settings::SettingsPlugin};
 
 pub mod main_menu;
 pub mod in_game;
+pub mod settings;
 
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, States)]
 enum AppState {
     #[default]
     MainMenu,
+    Settings,
     InGame,
 }
 
@@ -19,6 +21,7 @@ fn main() {
         .add_plugins(DefaultPlugins)
         .init_state::<AppState>()
         .add_plugins(MainMenuPlugin)
+        .add_plugins(SettingsPlugin)
         .add_plugins(InGamePlugin)
         .add_systems(Startup, setup_camera)
         .run();
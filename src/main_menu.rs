@@ -39,6 +39,7 @@ pub struct MainMenu;
 #[derive(Component, Clone, Copy, Debug)]
 pub enum MainMenuAction {
     NewGame,
+    Settings,
     Exit,
 }
 
@@ -47,6 +48,7 @@ impl MainMenuAction {
     pub fn label(&self) -> &'static str {
         match self {
             MainMenuAction::NewGame => "Start New Game",
+            MainMenuAction::Settings => "Settings",
             MainMenuAction::Exit => "Exit Game",
         }
     }
@@ -57,6 +59,7 @@ pub(crate) fn setup_main_menu_scene(
 ) {
     let menu_items = vec![
         MainMenuAction::NewGame,
+        MainMenuAction::Settings,
         MainMenuAction::Exit,
     ];
 
@@ -163,6 +166,10 @@ pub fn handle_main_menu_actions(
                     println!("New Game");
                     next_game_state.set(crate::AppState::InGame);
                 }
+                MainMenuAction::Settings => {
+                    println!("Settings");
+                    next_game_state.set(crate::AppState::Settings);
+                }
                 MainMenuAction::Exit => {
                     app_exit_events.write(AppExit::Success);
                 }
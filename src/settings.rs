@@ -0,0 +1,331 @@
+use bevy::app::{App, Plugin, Update};
+use bevy::ecs::schedule::{IntoScheduleConfigs, common_conditions::resource_changed};
+use bevy::ecs::{
+    component::Component,
+    entity::Entity,
+    query::{Changed, With},
+    system::{Commands, Query, Res, ResMut},
+};
+use bevy::ui::{BorderColor, UiRect};
+use bevy::{
+    color::Color,
+    ui::{BackgroundColor, Interaction, Node, Val, widget::Text},
+    utils::default,
+};
+use bevy_state::prelude::*;
+
+use crate::in_game::{GameConfig, format_rulestring, parse_rulestring};
+
+pub(crate) struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(crate::AppState::Settings), setup_settings_scene)
+            .add_systems(
+                Update,
+                handle_settings_actions.run_if(in_state(crate::AppState::Settings)),
+            )
+            .add_systems(
+                Update,
+                update_settings_summary
+                    .run_if(in_state(crate::AppState::Settings))
+                    .run_if(resource_changed::<GameConfig>),
+            )
+            .add_systems(OnExit(crate::AppState::Settings), cleanup_settings_scene);
+    }
+}
+
+#[derive(Component)]
+pub struct SettingsScene;
+
+#[derive(Component)]
+struct SettingsSummaryText;
+
+#[derive(Component)]
+struct BackButton;
+
+#[derive(Component, Clone, Copy, Debug)]
+enum RulePreset {
+    Conway,
+    HighLife,
+    Seeds,
+    DayAndNight,
+}
+
+impl RulePreset {
+    fn label(&self) -> &'static str {
+        match self {
+            RulePreset::Conway => "Conway (B3/S23)",
+            RulePreset::HighLife => "HighLife (B36/S23)",
+            RulePreset::Seeds => "Seeds (B2/S)",
+            RulePreset::DayAndNight => "Day & Night (B3678/S34678)",
+        }
+    }
+
+    fn rulestring(&self) -> &'static str {
+        match self {
+            RulePreset::Conway => "B3/S23",
+            RulePreset::HighLife => "B36/S23",
+            RulePreset::Seeds => "B2/S",
+            RulePreset::DayAndNight => "B3678/S34678",
+        }
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+enum ConfigAdjustment {
+    GridNumDown,
+    GridNumUp,
+    UpdateIntervalDown,
+    UpdateIntervalUp,
+    MaxLifeDown,
+    MaxLifeUp,
+    CountdownDown,
+    CountdownUp,
+}
+
+impl ConfigAdjustment {
+    fn label(&self) -> &'static str {
+        match self {
+            ConfigAdjustment::GridNumDown => "Grid -",
+            ConfigAdjustment::GridNumUp => "Grid +",
+            ConfigAdjustment::UpdateIntervalDown => "Interval -",
+            ConfigAdjustment::UpdateIntervalUp => "Interval +",
+            ConfigAdjustment::MaxLifeDown => "Max Life -",
+            ConfigAdjustment::MaxLifeUp => "Max Life +",
+            ConfigAdjustment::CountdownDown => "Countdown -",
+            ConfigAdjustment::CountdownUp => "Countdown +",
+        }
+    }
+}
+
+fn summary_text(config: &GameConfig) -> String {
+    format!(
+        "Rule: {}   Grid: {}x{}   Interval: {:.2}s   Max Life: {}   Countdown: {:.1}s",
+        format_rulestring(config),
+        config.grid_num,
+        config.grid_num,
+        config.update_interval,
+        config.max_life,
+        config.countdown_seconds
+    )
+}
+
+pub(crate) fn setup_settings_scene(mut commands: Commands, config: Res<GameConfig>) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                position_type: bevy::ui::PositionType::Relative,
+                flex_direction: bevy::ui::FlexDirection::Column,
+                justify_content: bevy::ui::JustifyContent::Center,
+                align_items: bevy::ui::AlignItems::Center,
+                ..default()
+            },
+            SettingsScene,
+            BackgroundColor(Color::srgba(0.2, 0.2, 0.2, 0.5)),
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn((
+                Node {
+                    width: Val::Percent(80.),
+                    justify_content: bevy::ui::JustifyContent::Center,
+                    align_items: bevy::ui::AlignItems::Center,
+                    ..default()
+                },
+                Text::new("Settings"),
+            ));
+
+            // Current config summary
+            parent.spawn((
+                SettingsSummaryText,
+                Node {
+                    width: Val::Percent(80.),
+                    justify_content: bevy::ui::JustifyContent::Center,
+                    align_items: bevy::ui::AlignItems::Center,
+                    ..default()
+                },
+                Text::new(summary_text(&config)),
+            ));
+
+            // Rule presets
+            parent
+                .spawn(Node {
+                    width: Val::Percent(80.),
+                    justify_content: bevy::ui::JustifyContent::Center,
+                    flex_wrap: bevy::ui::FlexWrap::Wrap,
+                    ..default()
+                })
+                .with_children(|row| {
+                    for preset in [
+                        RulePreset::Conway,
+                        RulePreset::HighLife,
+                        RulePreset::Seeds,
+                        RulePreset::DayAndNight,
+                    ] {
+                        row.spawn((
+                            preset,
+                            bevy::ui::widget::Button,
+                            Node {
+                                width: Val::Px(200.),
+                                height: Val::Px(30.),
+                                margin: UiRect {
+                                    left: Val::Px(6.),
+                                    right: Val::Px(6.),
+                                    top: Val::Px(6.),
+                                    bottom: Val::Px(0.),
+                                },
+                                justify_content: bevy::ui::JustifyContent::Center,
+                                align_items: bevy::ui::AlignItems::Center,
+                                ..default()
+                            },
+                            BorderColor::all(Color::WHITE),
+                            Text::new(preset.label().to_string()),
+                        ));
+                    }
+                });
+
+            // Grid/interval/max-life/countdown adjustments
+            parent
+                .spawn(Node {
+                    width: Val::Percent(80.),
+                    justify_content: bevy::ui::JustifyContent::Center,
+                    flex_wrap: bevy::ui::FlexWrap::Wrap,
+                    ..default()
+                })
+                .with_children(|row| {
+                    for adjustment in [
+                        ConfigAdjustment::GridNumDown,
+                        ConfigAdjustment::GridNumUp,
+                        ConfigAdjustment::UpdateIntervalDown,
+                        ConfigAdjustment::UpdateIntervalUp,
+                        ConfigAdjustment::MaxLifeDown,
+                        ConfigAdjustment::MaxLifeUp,
+                        ConfigAdjustment::CountdownDown,
+                        ConfigAdjustment::CountdownUp,
+                    ] {
+                        row.spawn((
+                            adjustment,
+                            bevy::ui::widget::Button,
+                            Node {
+                                width: Val::Px(110.),
+                                height: Val::Px(30.),
+                                margin: UiRect {
+                                    left: Val::Px(6.),
+                                    right: Val::Px(6.),
+                                    top: Val::Px(6.),
+                                    bottom: Val::Px(0.),
+                                },
+                                justify_content: bevy::ui::JustifyContent::Center,
+                                align_items: bevy::ui::AlignItems::Center,
+                                ..default()
+                            },
+                            BorderColor::all(Color::WHITE),
+                            Text::new(adjustment.label().to_string()),
+                        ));
+                    }
+                });
+
+            // Back
+            parent
+                .spawn(Node {
+                    width: Val::Percent(80.),
+                    justify_content: bevy::ui::JustifyContent::Center,
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        BackButton,
+                        bevy::ui::widget::Button,
+                        Node {
+                            width: Val::Px(160.),
+                            height: Val::Px(30.),
+                            margin: UiRect {
+                                top: Val::Px(10.),
+                                ..default()
+                            },
+                            justify_content: bevy::ui::JustifyContent::Center,
+                            align_items: bevy::ui::AlignItems::Center,
+                            ..default()
+                        },
+                        BorderColor::all(Color::WHITE),
+                        Text::new("Back".to_string()),
+                    ));
+                });
+        });
+}
+
+pub(crate) fn handle_settings_actions(
+    preset_query: Query<(&Interaction, &RulePreset), Changed<Interaction>>,
+    adjustment_query: Query<(&Interaction, &ConfigAdjustment), Changed<Interaction>>,
+    back_query: Query<&Interaction, (Changed<Interaction>, With<BackButton>)>,
+    mut config: ResMut<GameConfig>,
+    mut next_app_state: ResMut<NextState<crate::AppState>>,
+) {
+    for (interaction, preset) in preset_query.iter() {
+        if *interaction == Interaction::Pressed {
+            if let Some((birth, survive)) = parse_rulestring(preset.rulestring()) {
+                config.birth_neighbors = birth;
+                config.survive_neighbors = survive;
+            }
+        }
+    }
+
+    for (interaction, adjustment) in adjustment_query.iter() {
+        if *interaction == Interaction::Pressed {
+            match adjustment {
+                ConfigAdjustment::GridNumDown => {
+                    config.grid_num = config.grid_num.saturating_sub(10).max(10);
+                }
+                ConfigAdjustment::GridNumUp => {
+                    config.grid_num = (config.grid_num + 10).min(300);
+                }
+                ConfigAdjustment::UpdateIntervalDown => {
+                    config.update_interval = (config.update_interval - 0.1).max(0.05);
+                }
+                ConfigAdjustment::UpdateIntervalUp => {
+                    config.update_interval = (config.update_interval + 0.1).min(2.0);
+                }
+                ConfigAdjustment::MaxLifeDown => {
+                    config.max_life = config.max_life.saturating_sub(1).max(1);
+                }
+                ConfigAdjustment::MaxLifeUp => {
+                    config.max_life = (config.max_life + 1).min(10);
+                }
+                ConfigAdjustment::CountdownDown => {
+                    config.countdown_seconds = (config.countdown_seconds - 1.).max(0.);
+                }
+                ConfigAdjustment::CountdownUp => {
+                    config.countdown_seconds = (config.countdown_seconds + 1.).min(10.);
+                }
+            }
+        }
+    }
+
+    for interaction in back_query.iter() {
+        if *interaction == Interaction::Pressed {
+            next_app_state.set(crate::AppState::MainMenu);
+        }
+    }
+}
+
+pub(crate) fn update_settings_summary(
+    config: Res<GameConfig>,
+    mut text_query: Query<&mut Text, With<SettingsSummaryText>>,
+) {
+    for mut text in text_query.iter_mut() {
+        *text = Text::new(summary_text(&config));
+    }
+}
+
+/// delete entity in settings screen
+pub(crate) fn cleanup_settings_scene(
+    mut commands: Commands,
+    query: Query<Entity, With<SettingsScene>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}